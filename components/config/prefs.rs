@@ -114,6 +114,22 @@ pub struct Preferences {
     pub devtools_server_listen_address: String,
     // feature: WebGPU | #24706 | Web/API/WebGPU_API
     pub dom_webgpu_enabled: bool,
+    /// Report each successful `GPUBuffer.mapAsync()` as a "measure" performance entry
+    /// named after the buffer's label, lasting from the call until the mapping is ready.
+    pub dom_webgpu_performance_entries_enabled: bool,
+    /// Queue `GPUBuffer.mapAsync()` calls made while another map is pending or active,
+    /// instead of rejecting them, and start each once the buffer is unmapped.
+    pub dom_webgpu_queue_map_requests: bool,
+    /// Warn when a `GPUBuffer` that was mapped for reading is unmapped after script
+    /// wrote to its mapped ranges, as such writes are discarded. This checksums the
+    /// data of every read mapping, so it is meant for debugging.
+    pub dom_webgpu_report_discarded_writes: bool,
+    /// Log a console message when `GPUBuffer.unmap()` is called on a buffer that is
+    /// neither mapped nor being mapped, which is harmless but often unintended.
+    pub dom_webgpu_report_redundant_unmaps: bool,
+    /// Zero the data of each `GPUBuffer` mapping once it is unmapped, so that none of
+    /// it lingers in memory that is later reused.
+    pub dom_webgpu_scrub_unmapped_data: bool,
     /// List of comma-separated backends to be used by wgpu.
     pub dom_webgpu_wgpu_backend: String,
     // feature: AbortController | #34866 | Web/API/AbortController
@@ -466,6 +482,11 @@ impl Preferences {
             dom_web_animations_enabled: false,
             dom_webgl2_enabled: false,
             dom_webgpu_enabled: false,
+            dom_webgpu_performance_entries_enabled: false,
+            dom_webgpu_queue_map_requests: false,
+            dom_webgpu_report_discarded_writes: false,
+            dom_webgpu_report_redundant_unmaps: false,
+            dom_webgpu_scrub_unmapped_data: false,
             dom_webgpu_wgpu_backend: String::new(),
             dom_webrtc_enabled: false,
             dom_webrtc_transceiver_enabled: false,
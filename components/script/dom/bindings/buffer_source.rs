@@ -6,6 +6,8 @@
 
 #[cfg(feature = "webgpu")]
 use std::ffi::c_void;
+#[cfg(feature = "webgpu")]
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::marker::PhantomData;
 #[cfg(feature = "webgpu")]
 use std::ops::Range;
@@ -950,11 +952,32 @@ impl DataBlock {
         Arc::get_mut(&mut self.data).unwrap()
     }
 
+    /// A checksum of all of the data, including what script wrote through views,
+    /// for noticing writes that are never written back.
+    pub(crate) fn checksum(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.data.hash(&mut hasher);
+        hasher.finish()
+    }
+
     pub(crate) fn clear_views(&mut self) {
         self.data_views.clear()
     }
 
+    /// Zeroes all of the data, so that none of it lingers in memory once the block
+    /// is freed and the allocation reused. Nothing is zeroed if there is any active
+    /// view, as the data is then still shared with script.
+    pub(crate) fn scrub(&mut self) {
+        // `Arc::get_mut` ensures there are no views
+        if let Some(data) = Arc::get_mut(&mut self.data) {
+            data.fill(0);
+        }
+    }
+
     /// Returns error if requested range is already mapped
+    ///
+    /// Only overlapping views are rejected, whatever the mode of the mapping, so any
+    /// number of disjoint views can be read from at the same time.
     pub(crate) fn view(
         &mut self,
         cx: &mut js::context::JSContext,
@@ -160,6 +160,11 @@ impl Console {
     pub(crate) fn internal_warn(cx: &mut JSContext, global: &GlobalScope, message: String) {
         Console::send_string_message(cx, global, ConsoleLogLevel::Warn, message);
     }
+
+    // Directly logs a string message at the info level, without processing the message
+    pub(crate) fn internal_info(cx: &mut JSContext, global: &GlobalScope, message: String) {
+        Console::send_string_message(cx, global, ConsoleLogLevel::Info, message);
+    }
 }
 
 #[expect(unsafe_code)]
@@ -2,9 +2,12 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+use std::cell::Cell;
+use std::collections::VecDeque;
 use std::ops::Range;
 use std::rc::Rc;
 use std::string::String;
+use std::time::{Duration, Instant};
 
 use dom_struct::dom_struct;
 use js::context::JSContext;
@@ -13,8 +16,10 @@ use js::typedarray::HeapArrayBuffer;
 use script_bindings::cell::DomRefCell;
 use script_bindings::reflector::{Reflector, reflect_dom_object_with_cx};
 use script_bindings::trace::RootedTraceableBox;
+use servo_base::cross_process_instant::CrossProcessInstant;
 use servo_base::generic_channel::GenericSharedMemory;
-use webgpu_traits::{Mapping, WebGPU, WebGPUBuffer, WebGPURequest};
+use servo_config::pref;
+use webgpu_traits::{Mapping, WebGPU, WebGPUBuffer, WebGPUDevice, WebGPURequest};
 use wgpu_core::device::HostMap;
 use wgpu_core::resource::BufferAccessError;
 
@@ -25,10 +30,14 @@ use crate::dom::bindings::codegen::Bindings::WebGPUBinding::{
     GPUMapModeConstants, GPUMapModeFlags, GPUSize64,
 };
 use crate::dom::bindings::error::{Error, Fallible};
+use crate::dom::bindings::inheritance::Castable;
 use crate::dom::bindings::reflector::DomGlobal;
 use crate::dom::bindings::root::{Dom, DomRoot};
-use crate::dom::bindings::str::USVString;
+use crate::dom::bindings::str::{DOMString, USVString};
+use crate::dom::console::Console;
 use crate::dom::globalscope::GlobalScope;
+use crate::dom::performance::performanceentry::PerformanceEntry;
+use crate::dom::performance::performancemeasure::PerformanceMeasure;
 use crate::dom::promise::Promise;
 use crate::dom::webgpu::gpudevice::GPUDevice;
 use crate::routed_promise::{RoutedPromiseListener, callback_promise};
@@ -44,6 +53,15 @@ pub(crate) struct ActiveBufferMapping {
     mode: GPUMapModeFlags,
     /// <https://gpuweb.github.io/gpuweb/#active-buffer-mapping-range>
     range: Range<u64>,
+    /// Whether script may have written to `data`, which it can only do through the
+    /// views that `getMappedRange()` returned
+    written: bool,
+    /// Whether this is the mapping of a buffer that was mapped at creation, which is
+    /// writable whatever the usage of the buffer
+    mapped_at_creation: bool,
+    /// The checksum of `data` once the contents of a read mapping were loaded, when
+    /// writes that unmapping discards are reported
+    read_checksum: Option<u64>,
 }
 
 impl ActiveBufferMapping {
@@ -65,8 +83,228 @@ impl ActiveBufferMapping {
             data: DataBlock::new_zeroed(size),
             mode,
             range,
+            written: false,
+            mapped_at_creation: false,
+            read_checksum: None,
         }))
     }
+
+    fn is_write(&self) -> bool {
+        self.mode >= GPUMapModeConstants::WRITE
+    }
+
+    /// Loads the contents of the mapping from `src`. The data of a read mapping is
+    /// checksummed if writes to it are reported, see [`discarded_write_message`].
+    fn load(&mut self, src: &[u8]) {
+        self.data.load(src);
+        if pref!(dom_webgpu_report_discarded_writes) && !self.is_write() {
+            self.read_checksum = Some(self.data.checksum());
+        }
+    }
+
+    /// Records that script got a view of `data`, through which it may write to it.
+    fn mark_written(&mut self) {
+        self.written = true;
+    }
+
+    /// Validates `offset..offset + size` against this mapping like `getMappedRange()`
+    /// does, returning the range rebased to the start of `data` if it is valid.
+    ///
+    /// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-getmappedrange>
+    fn data_range(&self, offset: u64, size: u64) -> Option<Range<usize>> {
+        mapped_data_range(&self.range, offset, size)
+    }
+}
+
+/// The alignment that the WebGPU specification requires for the offset of a mapped
+/// range.
+///
+/// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-getmappedrange>
+pub(crate) const MAP_ALIGNMENT: u64 = 8;
+
+/// The alignment that the WebGPU specification requires for buffer sizes, copies and
+/// the size of a mapped range.
+///
+/// <https://gpuweb.github.io/gpuweb/#dom-gpudevice-createbuffer>
+pub(crate) const COPY_BUFFER_ALIGNMENT: u64 = 4;
+
+// Buffers are validated with the alignments of wgpu, so an upgrade of wgpu that changes
+// them would silently validate buffers differently than the specification requires.
+const _: () = assert!(wgpu_types::MAP_ALIGNMENT == MAP_ALIGNMENT);
+const _: () = assert!(wgpu_types::COPY_BUFFER_ALIGNMENT == COPY_BUFFER_ALIGNMENT);
+
+/// Validates `offset..offset + size` against a mapping of `mapped_range` like
+/// `getMappedRange()` does, returning the range rebased to the start of the mapped
+/// data if it is valid.
+///
+/// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-getmappedrange>
+pub(crate) fn mapped_data_range(
+    mapped_range: &Range<u64>,
+    offset: u64,
+    size: u64,
+) -> Option<Range<usize>> {
+    let end = offset.checked_add(size)?;
+    let valid = offset.is_multiple_of(wgpu_types::MAP_ALIGNMENT) &&
+        size.is_multiple_of(wgpu_types::COPY_BUFFER_ALIGNMENT) &&
+        offset >= mapped_range.start &&
+        end <= mapped_range.end;
+    if !valid {
+        return None;
+    }
+    // only mapping.range is mapped with mapping.range.start at 0
+    // so we need to rebase range to mapped.range
+    let start = (offset - mapped_range.start) as usize;
+    Some(start..start + size as usize)
+}
+
+/// A `mapAsync()` call waiting for the buffer to be unmapped, when map requests are
+/// queued.
+#[derive(JSTraceable, MallocSizeOf)]
+struct QueuedMap {
+    #[conditional_malloc_size_of]
+    promise: Rc<Promise>,
+    mode: u32,
+    offset: GPUSize64,
+    size: Option<GPUSize64>,
+}
+
+/// Checks that a buffer with `usage` can be mapped with `mode`, returning the message
+/// of the validation error to generate otherwise.
+///
+/// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-mapasync>
+pub(crate) fn validate_map_mode(usage: u32, mode: u32) -> Result<(), String> {
+    let usage = wgpu_types::BufferUsages::from_bits_retain(usage);
+    let required = match mode {
+        GPUMapModeConstants::READ => wgpu_types::BufferUsages::MAP_READ,
+        GPUMapModeConstants::WRITE => wgpu_types::BufferUsages::MAP_WRITE,
+        _ => return Err(String::from("Invalid MapModeFlags")),
+    };
+    if !usage.contains(required) {
+        return Err(format!(
+            "Buffer usage does not contain {required:?}, which is required by the map mode"
+        ));
+    }
+    Ok(())
+}
+
+/// Whether a mapping with `mode` can exist for a buffer with `usage`, which is the
+/// case for mappings allowed by [`validate_map_mode`] and for the write mapping of a
+/// buffer that was mapped at creation.
+pub(crate) fn is_consistent_mapping(usage: u32, mode: u32, mapped_at_creation: bool) -> bool {
+    (mapped_at_creation && mode == GPUMapModeConstants::WRITE) ||
+        validate_map_mode(usage, mode).is_ok()
+}
+
+/// Checks that a map of `offset..offset + size` of a buffer of `buffer_size` bytes
+/// stays within the buffer, so that such maps fail with a clear message before they
+/// are sent to the GPU process.
+///
+/// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-mapasync>
+pub(crate) fn validate_map_size(
+    offset: u64,
+    size: Option<u64>,
+    buffer_size: u64,
+) -> Result<(), String> {
+    let size = size.unwrap_or(buffer_size.saturating_sub(offset));
+    match offset.checked_add(size) {
+        Some(end) if end <= buffer_size => Ok(()),
+        Some(end) => Err(format!(
+            "Mapped range end {end} exceeds the buffer size of {buffer_size}"
+        )),
+        None => Err(String::from("Mapped range end overflows")),
+    }
+}
+
+/// Checks that the GPU process sent `data_len` bytes for a mapping of `mapped_range`,
+/// which the data of the mapping is sized to, returning an error message otherwise.
+pub(crate) fn validate_mapping_data(
+    mapped_range: &Range<u64>,
+    data_len: usize,
+) -> Result<(), String> {
+    let range_len = mapped_range.end.saturating_sub(mapped_range.start);
+    if range_len != data_len as u64 {
+        return Err(format!(
+            "Mapped data of {data_len} bytes doesn't match the mapped range of {range_len} bytes"
+        ));
+    }
+    Ok(())
+}
+
+/// Unmaps the `data` of a mapping of `mapped_range`, returning what is written back to
+/// the buffer, which is `None` unless the mapping is a write mapping that script
+/// `written` to. Otherwise, the mapping holds the same contents as the buffer.
+///
+/// The whole mapping is written back, not just the ranges that `getMappedRange()`
+/// returned, so that the bytes of the mapping outside of those keep the contents the
+/// mapping was initialized with, which are zeros for a fresh map.
+///
+/// The write back is a copy of `data`, which is scrubbed afterwards if `scrub` is set,
+/// so that none of the contents linger in memory that is later reused.
+pub(crate) fn unmap_data(
+    data: &mut DataBlock,
+    mapped_range: &Range<u64>,
+    written: bool,
+    scrub: bool,
+) -> Option<Mapping> {
+    let write_back = written.then(|| Mapping {
+        data: GenericSharedMemory::from_bytes(data.data()),
+        range: mapped_range.clone(),
+        mode: HostMap::Write,
+    });
+    if scrub {
+        data.scrub();
+    }
+    write_back
+}
+
+/// The warning logged when a read mapping of the buffer labelled `label` is unmapped
+/// with a data checksum of `checksum` that differs from the `loaded_checksum` it had
+/// once its contents were loaded, or `None` if script didn't write to it. Writes to a
+/// read mapping are never written back to the buffer.
+pub(crate) fn discarded_write_message(
+    label: &str,
+    loaded_checksum: u64,
+    checksum: u64,
+) -> Option<String> {
+    (loaded_checksum != checksum).then(|| {
+        format!(
+            "GPUBuffer \"{label}\" was written to while mapped for reading, and the writes \
+             are discarded on unmap"
+        )
+    })
+}
+
+/// A message for a map that failed because it reached past the end of the buffer, as
+/// such failures are otherwise indistinguishable from other invalid maps.
+pub(crate) fn map_error_message(error: &BufferAccessError) -> Option<String> {
+    match error {
+        BufferAccessError::OutOfBoundsOverrun { index, max } => Some(format!(
+            "Mapped range end {index} exceeds the buffer size of {max}"
+        )),
+        _ => None,
+    }
+}
+
+/// The number of buffers of a device that were garbage collected while they were still
+/// mapped, which usually means that an application forgets to unmap them. The count is
+/// shared by the device and its buffers, as a buffer can't safely reach its device
+/// while it is being finalized.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LeakedMappings(Rc<Cell<u64>>);
+
+impl LeakedMappings {
+    pub(crate) fn count(&self) -> u64 {
+        self.0.get()
+    }
+
+    /// Counts another leaked mapping, returning whether a summary should be logged,
+    /// which is the case whenever the count reaches a power of two so that leaks in
+    /// large applications don't flood the log.
+    pub(crate) fn record(&self) -> bool {
+        let count = self.0.get() + 1;
+        self.0.set(count);
+        count.is_power_of_two()
+    }
 }
 
 #[dom_struct]
@@ -77,6 +315,9 @@ pub(crate) struct GPUBuffer {
     label: DomRefCell<USVString>,
     #[no_trace]
     buffer: WebGPUBuffer,
+    /// The device that created the buffer. It never changes, and every request about
+    /// the buffer, including map requests, is made on behalf of this device, even if
+    /// the buffer ends up in a bind group of another device.
     device: Dom<GPUDevice>,
     /// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-size>
     size: GPUSize64,
@@ -87,6 +328,14 @@ pub(crate) struct GPUBuffer {
     pending_map: DomRefCell<Option<Rc<Promise>>>,
     /// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-mapping-slot>
     mapping: DomRefCell<Option<ActiveBufferMapping>>,
+    /// When the map in `pending_map` was requested
+    #[ignore_malloc_size_of = "Defined in std::time"]
+    map_requested_at: Cell<Option<Instant>>,
+    /// Maps requested while another one was pending or active, in request order
+    queued_maps: DomRefCell<VecDeque<QueuedMap>>,
+    #[no_trace]
+    #[ignore_malloc_size_of = "Shared with the device"]
+    leaked_mappings: LeakedMappings,
 }
 
 impl GPUBuffer {
@@ -109,6 +358,9 @@ impl GPUBuffer {
             size,
             usage,
             mapping: DomRefCell::new(mapping.map(|mapping| *mapping.into_box())),
+            map_requested_at: Cell::new(None),
+            queued_maps: Default::default(),
+            leaked_mappings: device.leaked_mappings(),
         }
     }
 
@@ -139,18 +391,18 @@ impl GPUBuffer {
         self.buffer
     }
 
+    /// The id of the device that created this buffer, which stays the same for the
+    /// lifetime of the buffer.
+    pub(crate) fn device_id(&self) -> WebGPUDevice {
+        self.device.id()
+    }
+
     /// <https://gpuweb.github.io/gpuweb/#dom-gpudevice-createbuffer>
     pub(crate) fn create(
         cx: &mut js::context::JSContext,
         device: &GPUDevice,
         descriptor: &GPUBufferDescriptor,
     ) -> Fallible<DomRoot<GPUBuffer>> {
-        let desc = wgpu_types::BufferDescriptor {
-            label: (&descriptor.parent).convert(),
-            size: descriptor.size as wgpu_types::BufferAddress,
-            usage: wgpu_types::BufferUsages::from_bits_retain(descriptor.usage),
-            mapped_at_creation: descriptor.mappedAtCreation,
-        };
         let id = device.global().wgpu_id_hub().create_buffer_id();
 
         device
@@ -159,16 +411,39 @@ impl GPUBuffer {
             .send(WebGPURequest::CreateBuffer {
                 device_id: device.id().0,
                 buffer_id: id,
-                descriptor: desc,
+                descriptor: Self::wgpu_descriptor(descriptor),
             })
             .expect("Failed to create WebGPU buffer");
 
-        let buffer = WebGPUBuffer(id);
+        Self::new_created(cx, device, WebGPUBuffer(id), descriptor)
+    }
+
+    fn wgpu_descriptor(descriptor: &GPUBufferDescriptor) -> wgpu_types::BufferDescriptor<'static> {
+        wgpu_types::BufferDescriptor {
+            label: (&descriptor.parent).convert(),
+            size: descriptor.size as wgpu_types::BufferAddress,
+            usage: wgpu_types::BufferUsages::from_bits_retain(descriptor.usage),
+            mapped_at_creation: descriptor.mappedAtCreation,
+        }
+    }
+
+    /// Creates the DOM object for `buffer` once its creation was requested from the
+    /// GPU process.
+    ///
+    /// A buffer that is mapped at creation starts with a write mapping of all of its
+    /// contents, so `getMappedRange()` can be called without `mapAsync()` until it is
+    /// unmapped.
+    fn new_created(
+        cx: &mut js::context::JSContext,
+        device: &GPUDevice,
+        buffer: WebGPUBuffer,
+        descriptor: &GPUBufferDescriptor,
+    ) -> Fallible<DomRoot<GPUBuffer>> {
         let mapping = if descriptor.mappedAtCreation {
-            Some(ActiveBufferMapping::new(
-                GPUMapModeConstants::WRITE,
-                0..descriptor.size,
-            )?)
+            let mut mapping =
+                ActiveBufferMapping::new(GPUMapModeConstants::WRITE, 0..descriptor.size)?;
+            mapping.mapped_at_creation = true;
+            Some(mapping)
         } else {
             None
         };
@@ -189,6 +464,13 @@ impl GPUBuffer {
 
 impl Drop for GPUBuffer {
     fn drop(&mut self) {
+        if self.mapping.borrow().is_some() && self.leaked_mappings.record() {
+            warn!(
+                "{} GPUBuffers were garbage collected while mapped, unmap buffers once \
+                 their mapped ranges are no longer needed",
+                self.leaked_mappings.count()
+            );
+        }
         if let Err(e) = self
             .channel
             .0
@@ -205,42 +487,33 @@ impl Drop for GPUBuffer {
 impl GPUBufferMethods<crate::DomTypeHolder> for GPUBuffer {
     /// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-unmap>
     fn Unmap(&self, cx: &mut js::context::JSContext) {
-        // Step 1
-        let promise = self.pending_map.borrow_mut().take();
-        if let Some(promise) = promise {
-            promise.reject_error(cx, Error::Abort(None));
-        }
-        // Step 2
-        let mut mapping = RootedTraceableBox::new(self.mapping.borrow_mut().take());
-        let mapping = if let Some(mapping) = mapping.as_mut() {
-            mapping
-        } else {
-            return;
-        };
-
-        // Step 3
-        mapping.data.clear_views();
-        // Step 5&7
-        if let Err(e) = self.channel.0.send(WebGPURequest::UnmapBuffer {
-            buffer_id: self.id().0,
-            mapping: if mapping.mode >= GPUMapModeConstants::WRITE {
-                Some(Mapping {
-                    data: GenericSharedMemory::from_bytes(mapping.data.data()),
-                    range: mapping.range.clone(),
-                    mode: HostMap::Write,
-                })
-            } else {
-                None
-            },
-        }) {
-            warn!("Failed to send Buffer unmap ({:?}) ({})", self.buffer.0, e);
+        // Unmapping a buffer that is neither mapped nor being mapped does nothing.
+        if pref!(dom_webgpu_report_redundant_unmaps) &&
+            self.MapState() == GPUBufferMapState::Unmapped
+        {
+            let message = format!(
+                "GPUBuffer \"{}\" was unmapped while it was not mapped, which does nothing",
+                self.label.borrow().0
+            );
+            Console::internal_info(cx, &self.global(), message);
         }
+        self.unmap(cx);
+        self.start_queued_map(cx);
     }
 
     /// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-destroy>
     fn Destroy(&self, cx: &mut JSContext) {
+        // Queued maps are waiting for an unmap after which the buffer can't be mapped.
+        let queued_maps = std::mem::take(&mut *self.queued_maps.borrow_mut());
+        for queued in queued_maps {
+            queued.promise.reject_error(cx, Error::Abort(None));
+        }
         // Step 1
-        self.Unmap(cx);
+        // The write back of `Unmap` is sent on the same channel as `DestroyBuffer`
+        // and the WGPU thread handles requests in order, so it lands before the
+        // buffer is destroyed. Queued maps were rejected above, so there are none to
+        // start, and destroying an unmapped buffer isn't a redundant unmap.
+        self.unmap(cx);
         // Step 2
         if let Err(e) = self
             .channel
@@ -263,47 +536,7 @@ impl GPUBufferMethods<crate::DomTypeHolder> for GPUBuffer {
         size: Option<GPUSize64>,
     ) -> Rc<Promise> {
         let promise = Promise::new_in_realm(cx);
-        // Step 2
-        if self.pending_map.borrow().is_some() {
-            promise.reject_error(cx, Error::Operation(None));
-            return promise;
-        }
-        // Step 4
-        *self.pending_map.borrow_mut() = Some(promise.clone());
-        // Step 5
-        let host_map = match mode {
-            GPUMapModeConstants::READ => HostMap::Read,
-            GPUMapModeConstants::WRITE => HostMap::Write,
-            _ => {
-                self.device
-                    .dispatch_error(webgpu_traits::Error::Validation(String::from(
-                        "Invalid MapModeFlags",
-                    )));
-                self.map_failure(cx, &promise);
-                return promise;
-            },
-        };
-
-        let callback = callback_promise(
-            &promise,
-            self,
-            self.global().task_manager().dom_manipulation_task_source(),
-        );
-        if let Err(e) = self.channel.0.send(WebGPURequest::BufferMapAsync {
-            callback,
-            buffer_id: self.buffer.0,
-            device_id: self.device.id().0,
-            host_map,
-            offset,
-            size,
-        }) {
-            warn!(
-                "Failed to send BufferMapAsync ({:?}) ({})",
-                self.buffer.0, e
-            );
-            self.map_failure(cx, &promise);
-            return promise;
-        }
+        self.map_async(cx, &promise, mode, offset, size);
         // Step 6
         promise
     }
@@ -328,24 +561,20 @@ impl GPUBufferMethods<crate::DomTypeHolder> for GPUBuffer {
             .map(RootedTraceableBox::new)
             .ok_or(Error::Operation(None))?;
 
-        let valid = offset.is_multiple_of(wgpu_types::MAP_ALIGNMENT) &&
-            range_size % wgpu_types::COPY_BUFFER_ALIGNMENT == 0 &&
-            offset >= mapping.range.start &&
-            offset + range_size <= mapping.range.end;
-        if !valid {
+        let Some(data_range) = mapping.data_range(offset, range_size) else {
             self.mapping.borrow_mut().replace(*mapping.into_box());
             return Err(Error::Operation(None));
-        }
+        };
 
         // Step 4
-        // only mapping.range is mapped with mapping.range.start at 0
-        // so we need to rebase range to mapped.range
-        let rebased_offset = (offset - mapping.range.start) as usize;
         let result = mapping
             .data
-            .view(cx, rebased_offset..rebased_offset + range_size as usize)
+            .view(cx, data_range)
             .map(|view| view.array_buffer())
             .map_err(|()| Error::Operation(None));
+        if result.is_ok() {
+            mapping.mark_written();
+        }
 
         self.mapping.borrow_mut().replace(*mapping.into_box());
         result
@@ -385,7 +614,157 @@ impl GPUBufferMethods<crate::DomTypeHolder> for GPUBuffer {
 }
 
 impl GPUBuffer {
-    fn map_failure(&self, cx: &mut JSContext, p: &Rc<Promise>) {
+    /// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-unmap>
+    fn unmap(&self, cx: &mut JSContext) {
+        // Step 1
+        let promise = self.pending_map.borrow_mut().take();
+        if let Some(promise) = promise {
+            promise.reject_error(cx, Error::Abort(None));
+            self.map_requested_at.set(None);
+        }
+        // Step 2
+        let mut mapping = RootedTraceableBox::new(self.mapping.borrow_mut().take());
+        let mapping = if let Some(mapping) = mapping.as_mut() {
+            mapping
+        } else {
+            return;
+        };
+
+        // A write mapping of a buffer without MAP_WRITE would write to a buffer that
+        // can't be written to by mapping, which `mapAsync()` doesn't allow.
+        debug_assert!(
+            is_consistent_mapping(self.usage, mapping.mode, mapping.mapped_at_creation),
+            "Mapping mode {} is inconsistent with buffer usage {}",
+            mapping.mode,
+            self.usage
+        );
+
+        // Step 3
+        mapping.data.clear_views();
+        if let Some(message) = mapping.read_checksum.and_then(|loaded_checksum| {
+            discarded_write_message(
+                &self.label.borrow().0,
+                loaded_checksum,
+                mapping.data.checksum(),
+            )
+        }) {
+            warn!("{message}");
+        }
+        // Step 5&7
+        // The views were cleared above, so the data is no longer shared with script
+        // and can be scrubbed.
+        let write_back = unmap_data(
+            &mut mapping.data,
+            &mapping.range,
+            mapping.is_write() && mapping.written,
+            pref!(dom_webgpu_scrub_unmapped_data),
+        );
+        if let Err(e) = self.channel.0.send(WebGPURequest::UnmapBuffer {
+            buffer_id: self.id().0,
+            mapping: write_back,
+        }) {
+            warn!("Failed to send Buffer unmap ({:?}) ({})", self.buffer.0, e);
+        }
+    }
+
+    /// Steps 2 to 5 of `mapAsync()`, settling `promise` once the map is done.
+    ///
+    /// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-mapasync>
+    fn map_async(
+        &self,
+        cx: &mut JSContext,
+        promise: &Rc<Promise>,
+        mode: u32,
+        offset: GPUSize64,
+        size: Option<GPUSize64>,
+    ) {
+        if pref!(dom_webgpu_queue_map_requests) &&
+            (self.pending_map.borrow().is_some() || self.mapping.borrow().is_some())
+        {
+            self.queued_maps.borrow_mut().push_back(QueuedMap {
+                promise: promise.clone(),
+                mode,
+                offset,
+                size,
+            });
+            return;
+        }
+        // Step 2
+        if self.pending_map.borrow().is_some() {
+            promise.reject_error(cx, Error::Operation(None));
+            return;
+        }
+        self.start_map(cx, promise, mode, offset, size);
+    }
+
+    /// Steps 4 and 5 of `mapAsync()`, once the map request is known to be valid.
+    ///
+    /// <https://gpuweb.github.io/gpuweb/#dom-gpubuffer-mapasync>
+    fn start_map(
+        &self,
+        cx: &mut JSContext,
+        promise: &Rc<Promise>,
+        mode: u32,
+        offset: GPUSize64,
+        size: Option<GPUSize64>,
+    ) {
+        // Step 4
+        *self.pending_map.borrow_mut() = Some(promise.clone());
+        self.map_requested_at.set(Some(Instant::now()));
+        // Step 5
+        if let Err(message) = validate_map_mode(self.usage, mode) {
+            self.device
+                .dispatch_error(webgpu_traits::Error::Validation(message));
+            self.map_failure(cx, promise, None);
+            return;
+        }
+        if let Err(message) = validate_map_size(offset, size, self.size) {
+            self.device
+                .dispatch_error(webgpu_traits::Error::Validation(message.clone()));
+            self.map_failure(cx, promise, Some(message));
+            return;
+        }
+        let host_map = if mode == GPUMapModeConstants::READ {
+            HostMap::Read
+        } else {
+            HostMap::Write
+        };
+
+        let callback = callback_promise(
+            promise,
+            self,
+            self.global().task_manager().dom_manipulation_task_source(),
+        );
+        if let Err(e) = self.channel.0.send(WebGPURequest::BufferMapAsync {
+            callback,
+            buffer_id: self.buffer.0,
+            // Always the creating device, which is what wgpu validates the map against.
+            device_id: self.device_id().0,
+            host_map,
+            offset,
+            size,
+        }) {
+            warn!(
+                "Failed to send BufferMapAsync ({:?}) ({})",
+                self.buffer.0, e
+            );
+            self.map_failure(cx, promise, None);
+        }
+    }
+
+    /// Starts the oldest queued map if the buffer is neither mapped nor waiting for a
+    /// map anymore.
+    fn start_queued_map(&self, cx: &mut JSContext) {
+        if self.pending_map.borrow().is_some() || self.mapping.borrow().is_some() {
+            return;
+        }
+        let Some(queued) = self.queued_maps.borrow_mut().pop_front() else {
+            return;
+        };
+        self.start_map(cx, &queued.promise, queued.mode, queued.offset, queued.size);
+    }
+
+    fn map_failure(&self, cx: &mut JSContext, p: &Rc<Promise>, message: Option<String>) {
         // Step 1
         if self.pending_map.borrow().as_ref() != Some(p) {
             assert!(p.is_rejected());
@@ -395,13 +774,33 @@ impl GPUBuffer {
         assert!(p.is_pending());
         // Step 3
         self.pending_map.borrow_mut().take();
+        self.map_requested_at.set(None);
         // Step 4
         let is_lost = self.device.is_lost();
         if is_lost {
             p.reject_error(cx, Error::Abort(None));
         } else {
-            p.reject_error(cx, Error::Operation(None));
+            p.reject_error(cx, Error::Operation(message));
         }
+        self.start_queued_map(cx);
+    }
+
+    /// Reports a successful map that took `latency` on the performance timeline.
+    fn queue_map_performance_entry(&self, latency: Duration) {
+        let Ok(duration) = time::Duration::try_from(latency) else {
+            return;
+        };
+        let global = self.global();
+        let entry = PerformanceMeasure::new(
+            &global,
+            DOMString::from(self.label.borrow().0.clone()),
+            CrossProcessInstant::now() - duration,
+            duration,
+            Default::default(),
+        );
+        global
+            .performance()
+            .queue_entry(entry.upcast::<PerformanceEntry>());
     }
 
     fn map_success(&self, cx: &mut js::context::JSContext, p: &Rc<Promise>, wgpu_mapping: Mapping) {
@@ -413,6 +812,14 @@ impl GPUBuffer {
 
         // Step 2
         assert!(p.is_pending());
+        // The data of the mapping is sized to its range, so data of another length
+        // can't be loaded into it.
+        if let Err(message) = validate_mapping_data(&wgpu_mapping.range, wgpu_mapping.data.len()) {
+            warn!("Rejecting map of buffer {:?}: {message}", self.buffer.0);
+            self.map_failure(cx, p, Some(message));
+            return;
+        }
+        let requested_at = self.map_requested_at.take();
 
         // Step 4
         let mapping = ActiveBufferMapping::new(
@@ -429,8 +836,14 @@ impl GPUBuffer {
                 p.reject_error(cx, error);
             },
             Ok(mut mapping) => {
+                if let Some(requested_at) = requested_at {
+                    let latency = requested_at.elapsed();
+                    if pref!(dom_webgpu_performance_entries_enabled) {
+                        self.queue_map_performance_entry(latency);
+                    }
+                }
                 // Step 5
-                mapping.data.load(&wgpu_mapping.data);
+                mapping.load(&wgpu_mapping.data);
                 // Step 6
                 self.mapping.borrow_mut().replace(*mapping.into_box());
                 // Step 7
@@ -450,7 +863,7 @@ impl RoutedPromiseListener<Result<Mapping, BufferAccessError>> for GPUBuffer {
     ) {
         match response {
             Ok(mapping) => self.map_success(cx, promise, mapping),
-            Err(_) => self.map_failure(cx, promise),
+            Err(error) => self.map_failure(cx, promise, map_error_message(&error)),
         }
     }
 }
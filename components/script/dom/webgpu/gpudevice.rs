@@ -55,7 +55,7 @@ use crate::dom::webgpu::gpuadapter::GPUAdapter;
 use crate::dom::webgpu::gpuadapterinfo::GPUAdapterInfo;
 use crate::dom::webgpu::gpubindgroup::GPUBindGroup;
 use crate::dom::webgpu::gpubindgrouplayout::GPUBindGroupLayout;
-use crate::dom::webgpu::gpubuffer::GPUBuffer;
+use crate::dom::webgpu::gpubuffer::{GPUBuffer, LeakedMappings};
 use crate::dom::webgpu::gpucommandencoder::GPUCommandEncoder;
 use crate::dom::webgpu::gpucomputepipeline::GPUComputePipeline;
 use crate::dom::webgpu::gpupipelinelayout::GPUPipelineLayout;
@@ -105,6 +105,10 @@ pub(crate) struct GPUDevice {
     lost_promise: DomRefCell<Rc<Promise>>,
     valid: Cell<bool>,
     droppable: DroppableGPUDevice,
+    /// Buffers of this device that were garbage collected while mapped
+    #[no_trace]
+    #[ignore_malloc_size_of = "Rc"]
+    leaked_mappings: LeakedMappings,
 }
 
 pub(crate) enum PipelineLayout {
@@ -146,6 +150,7 @@ impl GPUDevice {
             lost_promise: DomRefCell::new(lost_promise),
             valid: Cell::new(true),
             droppable: DroppableGPUDevice { channel, device },
+            leaked_mappings: Default::default(),
         }
     }
 
@@ -193,6 +198,12 @@ impl GPUDevice {
         self.droppable.device
     }
 
+    /// The counter of the buffers of this device that were garbage collected while
+    /// mapped, which is shared with those buffers.
+    pub(crate) fn leaked_mappings(&self) -> LeakedMappings {
+        self.leaked_mappings.clone()
+    }
+
     pub(crate) fn queue_id(&self) -> WebGPUQueue {
         self.default_queue.id()
     }
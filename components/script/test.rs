@@ -84,3 +84,96 @@ pub mod unminify {
     pub use crate::dom::html::htmlscriptelement::substitute_with_local_script;
     pub use crate::unminify::create_output_file;
 }
+
+#[cfg(feature = "webgpu")]
+pub mod gpubuffer {
+    use std::ops::Range;
+
+    use wgpu_core::resource::BufferAccessError;
+
+    use crate::dom::bindings::buffer_source::DataBlock;
+    use crate::dom::webgpu::gpubuffer::{self, LeakedMappings};
+
+    pub const MAP_ALIGNMENT: u64 = gpubuffer::MAP_ALIGNMENT;
+
+    pub const COPY_BUFFER_ALIGNMENT: u64 = gpubuffer::COPY_BUFFER_ALIGNMENT;
+
+    pub fn mapped_data_range(
+        mapped_range: &Range<u64>,
+        offset: u64,
+        size: u64,
+    ) -> Option<Range<usize>> {
+        gpubuffer::mapped_data_range(mapped_range, offset, size)
+    }
+
+    pub fn validate_map_mode(usage: u32, mode: u32) -> Result<(), String> {
+        gpubuffer::validate_map_mode(usage, mode)
+    }
+
+    pub fn is_consistent_mapping(usage: u32, mode: u32, mapped_at_creation: bool) -> bool {
+        gpubuffer::is_consistent_mapping(usage, mode, mapped_at_creation)
+    }
+
+    pub fn validate_map_size(
+        offset: u64,
+        size: Option<u64>,
+        buffer_size: u64,
+    ) -> Result<(), String> {
+        gpubuffer::validate_map_size(offset, size, buffer_size)
+    }
+
+    pub fn validate_mapping_data(mapped_range: &Range<u64>, data_len: usize) -> Result<(), String> {
+        gpubuffer::validate_mapping_data(mapped_range, data_len)
+    }
+
+    pub fn map_error_message(error: &BufferAccessError) -> Option<String> {
+        gpubuffer::map_error_message(error)
+    }
+
+    /// Counts a leaked mapping for each of `buffers` buffers of a device, returning the
+    /// count of the device and whether each buffer asked for a summary to be logged.
+    pub fn leak_mappings(buffers: usize) -> (u64, Vec<bool>) {
+        let device = LeakedMappings::default();
+        // Each buffer shares the counter of its device.
+        let summaries = (0..buffers).map(|_| device.clone().record()).collect();
+        (device.count(), summaries)
+    }
+
+    /// The range and the contents that unmapping a write mapping of `mapped_range`
+    /// holding `contents` writes back, if script `written` to the mapping.
+    pub fn unmap_write_back(
+        mapped_range: Range<u64>,
+        contents: &[u8],
+        written: bool,
+    ) -> Option<(Range<u64>, Vec<u8>)> {
+        let mut data = DataBlock::new_zeroed(contents.len());
+        data.load(contents);
+        gpubuffer::unmap_data(&mut data, &mapped_range, written, false)
+            .map(|write_back| (write_back.range, write_back.data.to_vec()))
+    }
+
+    /// Unmaps a written write mapping holding `contents` with unmapped data scrubbed,
+    /// returning the contents that are written back and those that the mapping holds
+    /// afterwards.
+    pub fn scrubbed_unmap(contents: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let mut data = DataBlock::new_zeroed(contents.len());
+        data.load(contents);
+        let write_back = gpubuffer::unmap_data(&mut data, &(0..contents.len() as u64), true, true)
+            .expect("A written write mapping is written back");
+        (write_back.data.to_vec(), data.data().to_vec())
+    }
+
+    /// The warning that unmapping a read mapping of the buffer labelled `label` logs
+    /// once `write` changed the data of the mapping, which was loaded with `contents`.
+    pub fn discarded_write_message(
+        label: &str,
+        contents: &[u8],
+        write: impl FnOnce(&mut [u8]),
+    ) -> Option<String> {
+        let mut data = DataBlock::new_zeroed(contents.len());
+        data.load(contents);
+        let loaded_checksum = data.checksum();
+        write(data.data());
+        gpubuffer::discarded_write_message(label, loaded_checksum, data.checksum())
+    }
+}
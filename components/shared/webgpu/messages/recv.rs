@@ -341,6 +341,8 @@ pub enum WebGPURequest {
         queue_id: QueueId,
         command_buffers: Vec<CommandBufferId>,
     },
+    /// Unmaps a buffer, after which the data written back is visible to any later GPU
+    /// operation using the buffer.
     UnmapBuffer {
         buffer_id: BufferId,
         /// Return back mapping for writeback
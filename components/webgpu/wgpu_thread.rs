@@ -193,6 +193,9 @@ impl WGPU {
                             host: host_map,
                             callback: Some(callback),
                         };
+                        // wgpu only calls back once the work submitted before this request
+                        // that uses the buffer has completed, so the mapped data reflects it
+                        // without an explicit fence.
                         let global = &self.global;
                         let result = global.buffer_map_async(buffer_id, offset, size, operation);
                         self.poller.wake();
@@ -553,6 +556,8 @@ impl WGPU {
                         }
                     },
                     WebGPURequest::DestroyBuffer(buffer) => {
+                        // Any `UnmapBuffer` for this buffer sent by `GPUBuffer.destroy()`
+                        // was received, and its data written back, before this request.
                         let global = &self.global;
                         global.buffer_destroy(buffer);
                     },
@@ -927,6 +932,10 @@ impl WGPU {
                             }
                             .copy_from_slice(&mapping.data);
                         }
+                        // No explicit barrier is needed for the GPU to see the data written
+                        // above: unmapping flushes the mapped range if its memory isn't
+                        // coherent, and wgpu's resource tracking inserts the barriers for the
+                        // transition to whatever usage the buffer is used with next.
                         // Ignore result because this operation always succeed from user perspective
                         let _result = global.buffer_unmap(buffer_id);
                     },
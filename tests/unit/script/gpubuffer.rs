@@ -0,0 +1,133 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use script::test::gpubuffer::{
+    COPY_BUFFER_ALIGNMENT, MAP_ALIGNMENT, discarded_write_message, is_consistent_mapping,
+    leak_mappings, map_error_message, mapped_data_range, scrubbed_unmap, unmap_write_back,
+    validate_map_mode, validate_map_size, validate_mapping_data,
+};
+use wgpu_core::resource::BufferAccessError;
+
+const COPY_DST: u32 = 0x0008;
+const MAP_READ: u32 = 0x0001;
+const MAP_WRITE: u32 = 0x0002;
+const MODE_READ: u32 = 0x0001;
+const MODE_WRITE: u32 = 0x0002;
+
+#[test]
+fn writes_to_read_mapping_are_reported() {
+    let contents = [1, 2, 3, 4, 5, 6, 7, 8];
+    assert_eq!(discarded_write_message("fred", &contents, |_| {}), None);
+    // Writing the bytes that are already there changes nothing.
+    let rewrite = |data: &mut [u8]| data[2..4].copy_from_slice(&[3, 4]);
+    assert_eq!(discarded_write_message("fred", &contents, rewrite), None);
+    let overwrite = |data: &mut [u8]| data[2..4].fill(0);
+    assert_eq!(
+        discarded_write_message("fred", &contents, overwrite).as_deref(),
+        Some(
+            "GPUBuffer \"fred\" was written to while mapped for reading, and the writes are \
+             discarded on unmap"
+        )
+    );
+}
+
+#[test]
+fn out_of_bounds_map_failure_names_the_buffer_size() {
+    let message = map_error_message(&BufferAccessError::OutOfBoundsOverrun {
+        index: 512,
+        max: 256,
+    })
+    .unwrap();
+    assert!(message.contains("buffer size of 256"), "{message}");
+    assert!(map_error_message(&BufferAccessError::NotMapped).is_none());
+}
+
+#[test]
+fn map_mode_must_match_buffer_usage() {
+    assert!(validate_map_mode(MAP_READ | COPY_DST, MODE_READ).is_ok());
+    assert!(validate_map_mode(MAP_WRITE, MODE_WRITE).is_ok());
+    assert!(validate_map_mode(MAP_READ | COPY_DST, MODE_WRITE).is_err());
+    assert!(validate_map_mode(MAP_WRITE, MODE_READ).is_err());
+    assert!(validate_map_mode(MAP_READ | MAP_WRITE, MODE_READ | MODE_WRITE).is_err());
+}
+
+#[test]
+fn read_usage_buffer_is_never_mapped_for_writing() {
+    // A write mapping of a MAP_READ buffer is what would make unmap() write back.
+    assert!(is_consistent_mapping(MAP_READ | COPY_DST, MODE_READ, false));
+    assert!(!is_consistent_mapping(
+        MAP_READ | COPY_DST,
+        MODE_WRITE,
+        false
+    ));
+    // Apart from the mapping of a buffer that was mapped at creation.
+    assert!(is_consistent_mapping(MAP_READ | COPY_DST, MODE_WRITE, true));
+}
+
+#[test]
+fn map_past_the_end_of_the_buffer_is_rejected() {
+    assert!(validate_map_size(0, None, 256).is_ok());
+    assert!(validate_map_size(64, Some(128), 256).is_ok());
+    assert!(validate_map_size(256, None, 256).is_ok());
+
+    let message = validate_map_size(128, Some(256), 256).unwrap_err();
+    assert!(message.contains("384"), "{message}");
+    assert!(message.contains("buffer size of 256"), "{message}");
+    assert!(validate_map_size(512, None, 256).is_err());
+    assert!(validate_map_size(u64::MAX, Some(8), 512).is_err());
+}
+
+#[test]
+fn leaked_mappings_are_counted_and_summarized_periodically() {
+    let (count, summaries) = leak_mappings(5);
+    assert_eq!(count, 5);
+    assert_eq!(summaries, [true, true, false, true, false]);
+}
+
+#[test]
+fn unmapping_a_written_sub_range_flushes_the_whole_mapping() {
+    // A fresh write mapping of bytes 16..48 of a buffer, of which script only got and
+    // wrote bytes 24..28, which are bytes 8..12 of the mapped data.
+    let mut contents = [0; 32];
+    contents[8..12].copy_from_slice(&[1, 2, 3, 4]);
+    let (range, data) = unmap_write_back(16..48, &contents, true).unwrap();
+    assert_eq!(range, 16..48);
+    assert_eq!(data.len(), 32);
+    assert_eq!(data[8..12], [1, 2, 3, 4]);
+    assert!(data[..8].iter().chain(&data[12..]).all(|&byte| byte == 0));
+}
+
+#[test]
+fn unmapping_an_unwritten_mapping_flushes_nothing() {
+    assert_eq!(unmap_write_back(16..48, &[0; 32], false), None);
+}
+
+#[test]
+fn unmapped_data_is_scrubbed_after_write_back() {
+    let contents = [1, 2, 3, 4, 5, 6, 7, 8];
+    let (write_back, data) = scrubbed_unmap(&contents);
+    // The write back is a copy, which scrubbing the data leaves as it was.
+    assert_eq!(write_back, contents);
+    assert_eq!(data, [0; 8]);
+}
+
+#[test]
+fn alignments_are_the_ones_the_specification_requires() {
+    assert_eq!(MAP_ALIGNMENT, 8);
+    assert_eq!(COPY_BUFFER_ALIGNMENT, 4);
+    // Mapped ranges are validated with those alignments.
+    let mapped_range = 0..32;
+    assert_eq!(mapped_data_range(&mapped_range, 4, 4), None);
+    assert_eq!(mapped_data_range(&mapped_range, 8, 2), None);
+    assert_eq!(mapped_data_range(&mapped_range, 8, 4), Some(8..12));
+}
+
+#[test]
+fn mapping_data_of_another_length_than_the_range_is_rejected() {
+    assert_eq!(validate_mapping_data(&(8..24), 16), Ok(()));
+    let error = validate_mapping_data(&(8..24), 12).unwrap_err();
+    assert!(error.contains("12 bytes"));
+    assert!(error.contains("16 bytes"));
+    assert!(validate_mapping_data(&(8..24), 32).is_err());
+}
@@ -4,6 +4,8 @@
 
 #[cfg(test)]
 mod encoding_detection;
+#[cfg(all(test, feature = "webgpu"))]
+mod gpubuffer;
 #[cfg(test)]
 mod htmlareaelement;
 #[cfg(test)]